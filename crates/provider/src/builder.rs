@@ -0,0 +1,56 @@
+use std::marker::PhantomData;
+
+use alloy_network::{Ethereum, Network, NetworkSigner};
+
+use crate::fillers::{JoinFill, SignerFiller};
+
+/// Builder for constructing a [`Provider`](crate::Provider) from a layered
+/// stack of [`TxFiller`](crate::fillers::TxFiller)s.
+///
+/// Each `with_*` method (see the individual fillers for the ones available,
+/// e.g. [`with_gas_pricing`](super::fillers::GasPriceFiller),
+/// [`with_eip1559_fees`](super::fillers::Eip1559Filler)) wraps the current
+/// stack in another [`JoinFill`], so the fillers run in the order they were
+/// added. [`signer`](Self::signer) is typically the last call, since signing
+/// needs every other field to already be filled.
+#[derive(Debug)]
+pub struct ProviderBuilder<F, N = Ethereum> {
+    filler: F,
+    _network: PhantomData<fn() -> N>,
+}
+
+impl<N: Network> Default for ProviderBuilder<(), N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ProviderBuilder<(), N> {
+    /// Create a new [`ProviderBuilder`] with an empty filler stack.
+    pub fn new() -> Self {
+        Self { filler: (), _network: PhantomData }
+    }
+}
+
+impl<F, N: Network> ProviderBuilder<F, N> {
+    /// Install a [`SignerFiller`] derived from `signer`, wiring the `from`
+    /// address and the signing step into the filler stack as a single step.
+    ///
+    /// This is the `SignerFiller` integration that
+    /// [`FromFiller`](crate::fillers::FromFiller)'s own docs refer to: `from`
+    /// no longer needs to be hard-coded, since it is read straight off `signer`.
+    pub fn signer<S>(self, signer: S) -> ProviderBuilder<JoinFill<F, SignerFiller<S, N>>, N>
+    where
+        S: NetworkSigner<N>,
+    {
+        ProviderBuilder {
+            filler: JoinFill::new(self.filler, SignerFiller::new(signer)),
+            _network: PhantomData,
+        }
+    }
+
+    /// Add an arbitrary [`TxFiller`](crate::fillers::TxFiller) to the stack.
+    pub fn filler<F2>(self, filler: F2) -> ProviderBuilder<JoinFill<F, F2>, N> {
+        ProviderBuilder { filler: JoinFill::new(self.filler, filler), _network: PhantomData }
+    }
+}
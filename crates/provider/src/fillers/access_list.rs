@@ -0,0 +1,144 @@
+use alloy_network::{Network, TransactionBuilder};
+use alloy_rpc_types::AccessListResult;
+use alloy_transport::TransportResult;
+
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+};
+
+/// A [`TxFiller`] that populates the access list of a transaction via
+/// `eth_createAccessList`.
+///
+/// This filler should be ordered after [`FromFiller`](super::FromFiller) (or
+/// [`SignerFiller`](super::SignerFiller)), since `eth_createAccessList` takes
+/// the `from` address of the request into account and an explicitly-provided
+/// `from` should win.
+///
+/// Transactions that already carry an access list are left untouched. Legacy
+/// transactions are skipped by default, since access lists only reduce gas
+/// cost for EIP-2930/1559 transactions; [`with_legacy`](Self::with_legacy)
+/// includes them.
+///
+/// # Example
+///
+/// ```
+/// # use alloy_network::{NetworkSigner, EthereumSigner, Ethereum};
+/// # use alloy_rpc_types::TransactionRequest;
+/// # use alloy_provider::{ProviderBuilder, RootProvider, Provider};
+/// # async fn test<S: NetworkSigner<Ethereum> + Clone>(url: url::Url, signer: S) -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = ProviderBuilder::new()
+///     .with_access_lists()
+///     .signer(signer)
+///     .on_http(url)?;
+///
+/// provider.send_transaction(TransactionRequest::default()).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AccessListFiller {
+    /// Whether to fold the `gasUsed` returned by `eth_createAccessList` into
+    /// the transaction's gas limit.
+    fill_gas_limit: bool,
+    /// Whether to compute an access list for legacy transactions too.
+    apply_to_legacy: bool,
+}
+
+impl AccessListFiller {
+    /// Create a new [`AccessListFiller`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also fold the `gasUsed` returned by `eth_createAccessList` into the gas
+    /// limit.
+    pub fn with_gas_limit(mut self, fill_gas_limit: bool) -> Self {
+        self.fill_gas_limit = fill_gas_limit;
+        self
+    }
+
+    /// Also compute an access list for legacy transactions.
+    pub fn with_legacy(mut self, apply_to_legacy: bool) -> Self {
+        self.apply_to_legacy = apply_to_legacy;
+        self
+    }
+}
+
+impl<N: Network> TxFiller<N> for AccessListFiller {
+    type Fillable = Option<AccessListResult>;
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        if tx.access_list().is_some() || (tx.gas_price().is_some() && !self.apply_to_legacy) {
+            FillerControlFlow::Finished
+        } else {
+            FillerControlFlow::Ready
+        }
+    }
+
+    async fn prepare<P, T>(
+        &self,
+        provider: &P,
+        tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: crate::Provider<T, N>,
+        T: alloy_transport::Transport + Clone,
+    {
+        if tx.gas_price().is_some() && !self.apply_to_legacy {
+            return Ok(None);
+        }
+
+        let result = provider.create_access_list(tx).await?;
+        Ok(Some(result))
+    }
+
+    async fn fill(
+        &self,
+        fillable: Self::Fillable,
+        mut tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        let Some(result) = fillable else {
+            return Ok(tx);
+        };
+
+        if let Some(builder) = tx.as_mut_builder() {
+            if builder.access_list().is_none() {
+                builder.set_access_list(result.access_list);
+            }
+            if self.fill_gas_limit {
+                let current = builder.gas_limit().unwrap_or_default();
+                builder.set_gas_limit(clamped_gas_limit(current, result.gas_used.to::<u64>()));
+            }
+        }
+        Ok(tx)
+    }
+}
+
+/// `gasUsed` from `eth_createAccessList` is the simulated total cost of the
+/// transaction with the access list applied, not a delta on top of an earlier
+/// estimate, so it is never added to the gas limit: the larger of the current
+/// limit and `gasUsed` is kept.
+fn clamped_gas_limit(current: u64, gas_used: u64) -> u64 {
+    current.max(gas_used)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_larger_existing_gas_limit() {
+        assert_eq!(clamped_gas_limit(100_000, 60_000), 100_000);
+    }
+
+    #[test]
+    fn adopts_simulated_gas_used_when_larger() {
+        assert_eq!(clamped_gas_limit(21_000, 80_000), 80_000);
+    }
+
+    #[test]
+    fn does_not_double_count_when_no_prior_estimate() {
+        assert_eq!(clamped_gas_limit(0, 60_000), 60_000);
+    }
+}
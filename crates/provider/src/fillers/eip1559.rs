@@ -0,0 +1,170 @@
+use alloy_eips::BlockNumberOrTag;
+use alloy_network::{Network, TransactionBuilder};
+use alloy_rpc_types::FeeHistory;
+use alloy_transport::TransportResult;
+
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+};
+
+/// Default number of recent blocks sampled by `eth_feeHistory`.
+const DEFAULT_FEE_HISTORY_WINDOW: u64 = 10;
+
+/// Default reward percentile used to derive the priority fee.
+const DEFAULT_REWARD_PERCENTILE: f64 = 20.0;
+
+/// Default multiplier applied to the latest base fee when computing `maxFeePerGas`.
+const DEFAULT_BASE_FEE_MULTIPLIER: u128 = 2;
+
+/// The 1559 fees computed by [`Eip1559Filler`] for a single transaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Eip1559Fees {
+    /// The `maxFeePerGas` to set on the transaction.
+    pub max_fee_per_gas: u128,
+    /// The `maxPriorityFeePerGas` to set on the transaction.
+    pub max_priority_fee_per_gas: u128,
+}
+
+/// A [`TxFiller`] that populates `maxFeePerGas` and `maxPriorityFeePerGas` from
+/// `eth_feeHistory`.
+///
+/// On `prepare`, this filler samples `eth_feeHistory` over the last
+/// [`fee_history_window`](Self::fee_history_window) blocks at the configured
+/// [`reward_percentile`](Self::reward_percentile), takes the median of the
+/// returned rewards as the priority fee, and combines it with the latest
+/// `baseFeePerGas` to compute `maxFeePerGas = base_fee * base_fee_multiplier +
+/// priority_fee`.
+///
+/// `status` returns [`FillerControlFlow::Finished`] once both 1559 fields are
+/// set, and transactions that already carry a legacy `gasPrice` are left
+/// untouched so users can opt into legacy pricing via
+/// [`GasPriceFiller`](super::GasPriceFiller) instead.
+///
+/// # Example
+///
+/// ```
+/// # use alloy_network::{NetworkSigner, EthereumSigner, Ethereum};
+/// # use alloy_rpc_types::TransactionRequest;
+/// # use alloy_provider::{ProviderBuilder, RootProvider, Provider};
+/// # async fn test<S: NetworkSigner<Ethereum> + Clone>(url: url::Url, signer: S) -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = ProviderBuilder::new()
+///     .with_eip1559_fees(Default::default())
+///     .signer(signer)
+///     .on_http(url)?;
+///
+/// provider.send_transaction(TransactionRequest::default()).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Eip1559Filler {
+    /// Number of recent blocks sampled by `eth_feeHistory`.
+    fee_history_window: u64,
+    /// Reward percentile requested from `eth_feeHistory`.
+    reward_percentile: f64,
+    /// Multiplier applied to the latest base fee when computing `maxFeePerGas`.
+    base_fee_multiplier: u128,
+}
+
+impl Default for Eip1559Filler {
+    fn default() -> Self {
+        Self {
+            fee_history_window: DEFAULT_FEE_HISTORY_WINDOW,
+            reward_percentile: DEFAULT_REWARD_PERCENTILE,
+            base_fee_multiplier: DEFAULT_BASE_FEE_MULTIPLIER,
+        }
+    }
+}
+
+impl Eip1559Filler {
+    /// Create a new [`Eip1559Filler`] with the default fee history window,
+    /// reward percentile and base-fee multiplier.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of recent blocks sampled by `eth_feeHistory`.
+    pub fn with_fee_history_window(mut self, fee_history_window: u64) -> Self {
+        self.fee_history_window = fee_history_window;
+        self
+    }
+
+    /// Set the reward percentile requested from `eth_feeHistory`.
+    pub fn with_reward_percentile(mut self, reward_percentile: f64) -> Self {
+        self.reward_percentile = reward_percentile;
+        self
+    }
+
+    /// Set the multiplier applied to the latest base fee when computing `maxFeePerGas`.
+    pub fn with_base_fee_multiplier(mut self, base_fee_multiplier: u128) -> Self {
+        self.base_fee_multiplier = base_fee_multiplier;
+        self
+    }
+}
+
+impl<N: Network> TxFiller<N> for Eip1559Filler {
+    type Fillable = Eip1559Fees;
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        if tx.gas_price().is_some() {
+            FillerControlFlow::Finished
+        } else if tx.max_fee_per_gas().is_some() && tx.max_priority_fee_per_gas().is_some() {
+            FillerControlFlow::Finished
+        } else {
+            FillerControlFlow::Ready
+        }
+    }
+
+    async fn prepare<P, T>(
+        &self,
+        provider: &P,
+        _tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: crate::Provider<T, N>,
+        T: alloy_transport::Transport + Clone,
+    {
+        let FeeHistory { base_fee_per_gas, reward, .. } = provider
+            .get_fee_history(
+                self.fee_history_window,
+                BlockNumberOrTag::Latest,
+                &[self.reward_percentile],
+            )
+            .await?;
+
+        let mut rewards: Vec<u128> = reward
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|block_rewards| block_rewards.first().copied())
+            .collect();
+        rewards.sort_unstable();
+        let priority_fee = rewards.get(rewards.len() / 2).copied().unwrap_or_default();
+
+        let base_fee = base_fee_per_gas.last().copied().unwrap_or_default();
+
+        Ok(Eip1559Fees {
+            max_fee_per_gas: base_fee * self.base_fee_multiplier + priority_fee,
+            max_priority_fee_per_gas: priority_fee,
+        })
+    }
+
+    async fn fill(
+        &self,
+        fillable: Self::Fillable,
+        mut tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        if let Some(builder) = tx.as_mut_builder() {
+            if builder.gas_price().is_some() {
+                return Ok(tx);
+            }
+            if builder.max_fee_per_gas().is_none() {
+                builder.set_max_fee_per_gas(fillable.max_fee_per_gas);
+            }
+            if builder.max_priority_fee_per_gas().is_none() {
+                builder.set_max_priority_fee_per_gas(fillable.max_priority_fee_per_gas);
+            }
+        }
+        Ok(tx)
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    cmp::max,
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::Address;
+use alloy_transport::TransportResult;
+
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+};
+
+/// The escalation strategy used by [`GasEscalatorFiller`] to raise the price of
+/// a stuck transaction over time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EscalatorStrategy {
+    /// Scale the original price by `coefficient.powf(elapsed_secs / every_secs)`
+    /// on each re-preparation.
+    Geometric {
+        /// Multiplier applied for every `every_secs` elapsed.
+        coefficient: f64,
+        /// Number of seconds between escalation steps.
+        every_secs: u64,
+    },
+    /// Add `increase_per_step` to the original price for every `every_secs`
+    /// elapsed.
+    Linear {
+        /// Amount added to the price on every escalation step.
+        increase_per_step: u128,
+        /// Number of seconds between escalation steps.
+        every_secs: u64,
+    },
+}
+
+impl EscalatorStrategy {
+    /// Compute the escalated price given the original price and elapsed time
+    /// since it was first seen.
+    fn escalate(&self, original: u128, elapsed: Duration) -> u128 {
+        match *self {
+            Self::Geometric { coefficient, every_secs } => {
+                let steps = elapsed.as_secs_f64() / every_secs.max(1) as f64;
+                let scaled = (original as f64) * coefficient.powf(steps);
+                max(scaled as u128, original)
+            }
+            Self::Linear { increase_per_step, every_secs } => {
+                let steps = elapsed.as_secs() / every_secs.max(1);
+                original + increase_per_step * steps as u128
+            }
+        }
+    }
+}
+
+/// Longest amount of time a tracked `(from, nonce)` baseline is kept before it
+/// is evicted, on the assumption that a transaction stuck for this long has
+/// either landed or been abandoned by the caller.
+const MAX_TRACKED_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The escalated price computed by [`GasEscalatorFiller`] for a single
+/// transaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct EscalatedPrice {
+    /// The escalated legacy gas price.
+    price: u128,
+    /// The factor, expressed in parts-per-million, by which 1559 fields are
+    /// scaled relative to the first-seen price.
+    factor_ppm: u128,
+}
+
+/// A [`TxFiller`] that bumps the gas price of an already-submitted-but-pending
+/// transaction on each re-preparation, for users retrying transactions that are
+/// stuck in the mempool.
+///
+/// The first time a given `(from, nonce)` pair is seen, its price is recorded
+/// as the baseline. On every subsequent `fill`, the configured
+/// [`EscalatorStrategy`] is applied to that baseline based on elapsed time,
+/// capped at `max_price`. For EIP-1559 transactions, `maxFeePerGas` and
+/// `maxPriorityFeePerGas` are scaled together by the same factor.
+///
+/// This filler only acts on transactions that already carry a price set by an
+/// earlier filler (e.g. [`GasPriceFiller`](super::GasPriceFiller) or
+/// [`Eip1559Filler`](super::Eip1559Filler)) — it never assigns the initial
+/// price itself.
+///
+/// # Example
+///
+/// ```
+/// # use alloy_network::{NetworkSigner, EthereumSigner, Ethereum};
+/// # use alloy_rpc_types::TransactionRequest;
+/// # use alloy_provider::{ProviderBuilder, RootProvider, Provider};
+/// # use alloy_provider::fillers::EscalatorStrategy;
+/// # async fn test<S: NetworkSigner<Ethereum> + Clone>(url: url::Url, signer: S) -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = ProviderBuilder::new()
+///     .with_gas_escalator(
+///         EscalatorStrategy::Geometric { coefficient: 1.125, every_secs: 30 },
+///         200_000_000_000,
+///     )
+///     .signer(signer)
+///     .on_http(url)?;
+///
+/// provider.send_transaction(TransactionRequest::default()).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GasEscalatorFiller {
+    strategy: EscalatorStrategy,
+    max_price: u128,
+    tracked: Arc<Mutex<HashMap<(Address, u64), (u128, Instant)>>>,
+}
+
+impl GasEscalatorFiller {
+    /// Create a new [`GasEscalatorFiller`] with the given strategy and price
+    /// cap.
+    pub fn new(strategy: EscalatorStrategy, max_price: u128) -> Self {
+        Self { strategy, max_price, tracked: Arc::new(Mutex::new(HashMap::new())) }
+    }
+}
+
+impl<N: Network> TxFiller<N> for GasEscalatorFiller {
+    type Fillable = EscalatedPrice;
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        if tx.gas_price().is_some() || tx.max_fee_per_gas().is_some() {
+            FillerControlFlow::Ready
+        } else {
+            FillerControlFlow::Finished
+        }
+    }
+
+    async fn prepare<P, T>(
+        &self,
+        _provider: &P,
+        tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: crate::Provider<T, N>,
+        T: alloy_transport::Transport + Clone,
+    {
+        let from = tx.from().unwrap_or_default();
+        let nonce = tx.nonce().unwrap_or_default();
+        let current = tx.gas_price().or(tx.max_fee_per_gas()).unwrap_or_default();
+
+        let (original, first_seen) = {
+            let mut tracked = self.tracked.lock().unwrap();
+            // Bound the map: a lower nonce for the same sender has already been confirmed or
+            // superseded, and anything older than `MAX_TRACKED_AGE` is assumed abandoned.
+            tracked.retain(|&(tracked_from, tracked_nonce), &mut (_, first_seen)| {
+                first_seen.elapsed() < MAX_TRACKED_AGE && !(tracked_from == from && tracked_nonce < nonce)
+            });
+            *tracked.entry((from, nonce)).or_insert((current, Instant::now()))
+        };
+
+        let escalated = capped_escalation(&self.strategy, original, first_seen.elapsed(), self.max_price);
+        let factor_ppm = if original == 0 { 1_000_000 } else { escalated * 1_000_000 / original };
+
+        Ok(EscalatedPrice { price: escalated, factor_ppm })
+    }
+
+    async fn fill(
+        &self,
+        fillable: Self::Fillable,
+        mut tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        if let Some(builder) = tx.as_mut_builder() {
+            if builder.gas_price().is_some() {
+                builder.set_gas_price(fillable.price);
+            } else if let (Some(max_fee), Some(priority_fee)) =
+                (builder.max_fee_per_gas(), builder.max_priority_fee_per_gas())
+            {
+                builder.set_max_fee_per_gas(max_fee * fillable.factor_ppm / 1_000_000);
+                builder.set_max_priority_fee_per_gas(priority_fee * fillable.factor_ppm / 1_000_000);
+            }
+        }
+        Ok(tx)
+    }
+}
+
+/// Apply `strategy` to `original` given the elapsed time, capped at `max_price`.
+fn capped_escalation(
+    strategy: &EscalatorStrategy,
+    original: u128,
+    elapsed: Duration,
+    max_price: u128,
+) -> u128 {
+    strategy.escalate(original, elapsed).min(max_price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geometric_scales_up_over_time() {
+        let strategy = EscalatorStrategy::Geometric { coefficient: 2.0, every_secs: 10 };
+        assert_eq!(strategy.escalate(100, Duration::from_secs(0)), 100);
+        assert_eq!(strategy.escalate(100, Duration::from_secs(10)), 200);
+    }
+
+    #[test]
+    fn geometric_never_drops_below_original() {
+        let strategy = EscalatorStrategy::Geometric { coefficient: 0.5, every_secs: 10 };
+        assert_eq!(strategy.escalate(100, Duration::from_secs(10)), 100);
+    }
+
+    #[test]
+    fn linear_adds_per_step() {
+        let strategy = EscalatorStrategy::Linear { increase_per_step: 5, every_secs: 10 };
+        assert_eq!(strategy.escalate(100, Duration::from_secs(0)), 100);
+        assert_eq!(strategy.escalate(100, Duration::from_secs(25)), 110);
+    }
+
+    #[test]
+    fn zero_every_secs_does_not_panic() {
+        let geometric = EscalatorStrategy::Geometric { coefficient: 2.0, every_secs: 0 };
+        let linear = EscalatorStrategy::Linear { increase_per_step: 5, every_secs: 0 };
+
+        geometric.escalate(100, Duration::from_secs(3));
+        linear.escalate(100, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn capped_escalation_respects_max_price() {
+        let strategy = EscalatorStrategy::Geometric { coefficient: 10.0, every_secs: 1 };
+        assert_eq!(capped_escalation(&strategy, 100, Duration::from_secs(5), 1_000), 1_000);
+    }
+}
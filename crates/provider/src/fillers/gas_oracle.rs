@@ -0,0 +1,179 @@
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use alloy_network::{Network, TransactionBuilder};
+use alloy_transport::TransportResult;
+
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+};
+
+/// A source of gas price estimates, used by [`GasOracleFiller`].
+///
+/// Implement this trait to plug in an external gas price estimator (e.g. a
+/// third-party gas station API) in place of the node's own `eth_gasPrice`.
+pub trait GasOracle: Send + Sync + 'static {
+    /// Fetch a fresh gas price estimate.
+    ///
+    /// Written as an explicit `-> impl Future<..> + Send` rather than `async
+    /// fn` so the returned future's `Send`-ness is provable for generic `O:
+    /// GasOracle`, which [`GasOracleFiller::spawn_refresh`] relies on to hand
+    /// the future to `tokio::spawn`.
+    fn fetch(&self) -> impl std::future::Future<Output = TransportResult<u128>> + Send;
+}
+
+/// A [`GasOracle`] that queries the node's own `eth_gasPrice` through the
+/// configured provider. Pass it to [`GasOracleFiller::new`] to use the node's
+/// own gas price.
+#[derive(Debug)]
+pub struct ProviderGasOracle<P, T, N> {
+    provider: P,
+    _marker: PhantomData<fn() -> (T, N)>,
+}
+
+impl<P: Clone, T, N> Clone for ProviderGasOracle<P, T, N> {
+    fn clone(&self) -> Self {
+        Self { provider: self.provider.clone(), _marker: PhantomData }
+    }
+}
+
+impl<P, T, N> ProviderGasOracle<P, T, N> {
+    /// Create a new [`ProviderGasOracle`] wrapping the given provider.
+    pub fn new(provider: P) -> Self {
+        Self { provider, _marker: PhantomData }
+    }
+}
+
+impl<P, T, N> GasOracle for ProviderGasOracle<P, T, N>
+where
+    P: crate::Provider<T, N> + Send + Sync + 'static,
+    T: alloy_transport::Transport + Clone + Send + Sync + 'static,
+    N: Network + Send + Sync + 'static,
+{
+    fn fetch(&self) -> impl std::future::Future<Output = TransportResult<u128>> + Send {
+        self.provider.get_gas_price()
+    }
+}
+
+/// A cached gas price along with the instant it was fetched.
+#[derive(Clone, Copy, Debug)]
+struct Cached {
+    price: u128,
+    fetched_at: Instant,
+}
+
+/// A [`TxFiller`] that populates the legacy gas price from a pluggable
+/// [`GasOracle`], refreshing the cached value on a configurable interval.
+///
+/// Unlike [`GasPriceFiller`](super::GasPriceFiller), which caches the first
+/// fetched price forever, this filler stores `(price, fetched_at)` behind a
+/// mutex and re-fetches from the oracle once the cached value is older than
+/// `ttl`. Pair this with [`spawn_refresh`](Self::spawn_refresh) to refresh the
+/// price on a background task so the hot path never blocks on a fetch.
+///
+/// # Example
+///
+/// ```
+/// # use alloy_network::{NetworkSigner, EthereumSigner, Ethereum};
+/// # use alloy_rpc_types::TransactionRequest;
+/// # use alloy_provider::{ProviderBuilder, RootProvider, Provider};
+/// # use std::time::Duration;
+/// # async fn test<S: NetworkSigner<Ethereum> + Clone>(url: url::Url, signer: S) -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = ProviderBuilder::new()
+///     .with_gas_oracle(Duration::from_secs(15))
+///     .signer(signer)
+///     .on_http(url)?;
+///
+/// provider.send_transaction(TransactionRequest::default()).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GasOracleFiller<O> {
+    oracle: Arc<O>,
+    ttl: Duration,
+    cache: Arc<Mutex<Option<Cached>>>,
+}
+
+impl<O: GasOracle> GasOracleFiller<O> {
+    /// Create a new [`GasOracleFiller`] wrapping the given oracle, refreshing
+    /// the cached price once it is older than `ttl`.
+    pub fn new(oracle: O, ttl: Duration) -> Self {
+        Self { oracle: Arc::new(oracle), ttl, cache: Arc::new(Mutex::new(None)) }
+    }
+
+    /// Returns the cached price if it is younger than `ttl`.
+    fn cached(&self) -> Option<u128> {
+        let cache = self.cache.lock().unwrap();
+        cache.as_ref().filter(|c| c.fetched_at.elapsed() < self.ttl).map(|c| c.price)
+    }
+
+    async fn refresh(&self) -> TransportResult<u128> {
+        let price = self.oracle.fetch().await?;
+        *self.cache.lock().unwrap() = Some(Cached { price, fetched_at: Instant::now() });
+        Ok(price)
+    }
+
+    /// Spawn a background task that refreshes the cached price every `ttl`, so
+    /// `prepare` usually finds a warm cache instead of blocking on a fetch.
+    ///
+    /// The first refresh happens only after one `ttl` has elapsed, and each
+    /// cached price expires before the next refresh completes; in both cases
+    /// `prepare` fetches from the oracle itself.
+    pub fn spawn_refresh(&self) -> tokio::task::JoinHandle<()>
+    where
+        O: 'static,
+    {
+        let this = self.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(this.ttl).await;
+                let _ = this.refresh().await;
+            }
+        })
+    }
+}
+
+impl<N: Network, O: GasOracle> TxFiller<N> for GasOracleFiller<O> {
+    type Fillable = u128;
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        if tx.gas_price().is_some() {
+            FillerControlFlow::Finished
+        } else {
+            FillerControlFlow::Ready
+        }
+    }
+
+    async fn prepare<P, T>(
+        &self,
+        _provider: &P,
+        _tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: crate::Provider<T, N>,
+        T: alloy_transport::Transport + Clone,
+    {
+        match self.cached() {
+            Some(price) => Ok(price),
+            None => self.refresh().await,
+        }
+    }
+
+    async fn fill(
+        &self,
+        fillable: Self::Fillable,
+        mut tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        if let Some(builder) = tx.as_mut_builder() {
+            if builder.gas_price().is_none() {
+                builder.set_gas_price(fillable);
+            }
+        }
+        Ok(tx)
+    }
+}
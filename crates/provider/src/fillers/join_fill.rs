@@ -0,0 +1,106 @@
+use alloy_network::Network;
+use alloy_transport::TransportResult;
+
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+};
+
+/// A [`TxFiller`] that joins two fillers into one, running the left filler
+/// before the right on both `prepare` and `fill`.
+///
+/// [`ProviderBuilder`](crate::ProviderBuilder) uses this to chain each
+/// `with_*`/`signer` call onto the existing filler stack without the caller
+/// having to name the combined type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JoinFill<L, R> {
+    left: L,
+    right: R,
+}
+
+impl<L, R> JoinFill<L, R> {
+    /// Create a new [`JoinFill`] running `left` before `right`.
+    pub fn new(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+}
+
+/// The empty filler stack: does nothing, and is always finished. This is the
+/// starting point for [`ProviderBuilder::new`](crate::ProviderBuilder::new),
+/// with each `with_*`/`signer` call wrapping it in another [`JoinFill`].
+impl<N: Network> TxFiller<N> for () {
+    type Fillable = ();
+
+    fn status(&self, _tx: &N::TransactionRequest) -> FillerControlFlow {
+        FillerControlFlow::Finished
+    }
+
+    async fn prepare<P, T>(
+        &self,
+        _provider: &P,
+        _tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: crate::Provider<T, N>,
+        T: alloy_transport::Transport + Clone,
+    {
+        Ok(())
+    }
+
+    async fn fill(
+        &self,
+        _fillable: Self::Fillable,
+        tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        Ok(tx)
+    }
+}
+
+impl<L, R, N> TxFiller<N> for JoinFill<L, R>
+where
+    N: Network,
+    L: TxFiller<N>,
+    R: TxFiller<N>,
+{
+    type Fillable = (L::Fillable, R::Fillable);
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        match (self.left.status(tx), self.right.status(tx)) {
+            (FillerControlFlow::Finished, FillerControlFlow::Finished) => {
+                FillerControlFlow::Finished
+            }
+            (FillerControlFlow::Missing(mut left), FillerControlFlow::Missing(right)) => {
+                left.extend(right);
+                FillerControlFlow::Missing(left)
+            }
+            (FillerControlFlow::Missing(missing), _) | (_, FillerControlFlow::Missing(missing)) => {
+                FillerControlFlow::Missing(missing)
+            }
+            _ => FillerControlFlow::Ready,
+        }
+    }
+
+    async fn prepare<P, T>(
+        &self,
+        provider: &P,
+        tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: crate::Provider<T, N>,
+        T: alloy_transport::Transport + Clone,
+    {
+        let left = self.left.prepare(provider, tx).await?;
+        let right = self.right.prepare(provider, tx).await?;
+        Ok((left, right))
+    }
+
+    async fn fill(
+        &self,
+        fillable: Self::Fillable,
+        tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        let (left, right) = fillable;
+        let tx = self.left.fill(left, tx).await?;
+        self.right.fill(right, tx).await
+    }
+}
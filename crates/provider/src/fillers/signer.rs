@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+
+use alloy_network::{Network, NetworkSigner, TransactionBuilder};
+use alloy_primitives::Address;
+use alloy_transport::{TransportErrorKind, TransportResult};
+
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+};
+
+/// A [`TxFiller`] that derives the `from` address from a configured
+/// [`NetworkSigner`] and signs the transaction once the rest of the filler
+/// stack has populated it.
+///
+/// This closes the gap left by [`FromFiller`](super::FromFiller), which
+/// requires the caller to hard-code the sender: here the address is read
+/// straight off the signer, so `NonceFiller` can fetch the correct nonce
+/// before the transaction is ever signed. Once a full [`SendableTx::Builder`]
+/// reaches `fill`, it is converted into a signed [`SendableTx::Envelope`],
+/// ready to be broadcast with `eth_sendRawTransaction`.
+///
+/// [`ProviderBuilder::signer`] appends this filler to the end of the current
+/// filler stack; it is the `SignerFiller` that the doc comment on `FromFiller`
+/// refers to.
+///
+/// [`ProviderBuilder::signer`]: crate::ProviderBuilder::signer
+#[derive(Debug)]
+pub struct SignerFiller<S, N> {
+    signer: S,
+    _network: PhantomData<fn() -> N>,
+}
+
+impl<S: Clone, N> Clone for SignerFiller<S, N> {
+    fn clone(&self) -> Self {
+        Self { signer: self.signer.clone(), _network: PhantomData }
+    }
+}
+
+impl<S, N> SignerFiller<S, N>
+where
+    N: Network,
+    S: NetworkSigner<N>,
+{
+    /// Create a new [`SignerFiller`] from a [`NetworkSigner`].
+    pub fn new(signer: S) -> Self {
+        Self { signer, _network: PhantomData }
+    }
+
+    /// The address that `from` will be filled with.
+    fn from(&self) -> Address {
+        self.signer.address()
+    }
+}
+
+impl<S, N> TxFiller<N> for SignerFiller<S, N>
+where
+    N: Network,
+    S: NetworkSigner<N> + Send + Sync,
+{
+    type Fillable = ();
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        // Signing always has work to do while the request is still a builder (even if `from`
+        // was already set, e.g. a `GasEscalatorFiller` re-preparing a retried transaction), so
+        // this never reports `Finished`. But it should not report `Ready` either until the
+        // fields `build_unsigned` needs are actually present — otherwise a misconfigured stack
+        // (no `NonceFiller`/gas filler upstream) spins here forever instead of surfacing a
+        // clear "missing field" error.
+        let mut missing = Vec::new();
+        if tx.nonce().is_none() {
+            missing.push("nonce");
+        }
+        if tx.gas_limit().is_none() {
+            missing.push("gas_limit");
+        }
+        if tx.gas_price().is_none() && tx.max_fee_per_gas().is_none() {
+            missing.push("gas_price/max_fee_per_gas");
+        }
+
+        if missing.is_empty() {
+            FillerControlFlow::Ready
+        } else {
+            FillerControlFlow::Missing(vec![("SignerFiller", missing)])
+        }
+    }
+
+    async fn prepare<P, T>(
+        &self,
+        _provider: &P,
+        _tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: crate::Provider<T, N>,
+        T: alloy_transport::Transport + Clone,
+    {
+        Ok(())
+    }
+
+    async fn fill(
+        &self,
+        _fillable: Self::Fillable,
+        tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        let SendableTx::Builder(mut builder) = tx else {
+            return Ok(tx);
+        };
+
+        if builder.from().is_none() {
+            builder.set_from(self.from());
+        }
+
+        let unsigned = match builder.build_unsigned() {
+            Ok(unsigned) => unsigned,
+            Err(incomplete) => return Ok(SendableTx::Builder(incomplete.tx)),
+        };
+
+        let envelope = self
+            .signer
+            .sign_transaction_from(self.from(), unsigned)
+            .await
+            .map_err(TransportErrorKind::custom)?;
+
+        Ok(SendableTx::Envelope(envelope))
+    }
+}